@@ -0,0 +1,4 @@
+//! Tools for working with data exported from TI calculators.
+
+pub mod size;
+pub mod vars;
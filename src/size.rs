@@ -0,0 +1,169 @@
+//! Human-readable formatting and parsing of byte quantities.
+
+use std::error::Error;
+use std::fmt;
+
+const UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const STEP: f64 = 1024.0;
+
+/// Error returned when a string cannot be parsed as a byte size by [`parse_size`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSizeError {
+    /// The string has no recognizable numeric portion.
+    MissingNumber,
+    /// The numeric portion could not be parsed as a number.
+    InvalidNumber,
+    /// The unit suffix was not one of the recognized binary units (e.g. `KiB`, `MiB`).
+    UnknownUnit,
+}
+
+impl fmt::Display for ParseSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ParseSizeError::MissingNumber => "missing a numeric size",
+            ParseSizeError::InvalidNumber => "could not parse the numeric size",
+            ParseSizeError::UnknownUnit => "unrecognized size unit",
+        };
+        f.write_str(message)
+    }
+}
+
+impl Error for ParseSizeError {}
+
+/// Formats `bytes` as a human-readable quantity using binary (KiB/MiB/...) units, rounded to
+/// `precision` decimal places.
+///
+/// Values under 1024 bytes are always reported as a whole number of bytes, regardless of
+/// `precision`.
+pub fn format_size_with_precision(bytes: usize, precision: usize) -> String {
+    let mut value = bytes as f64;
+    let mut unit_index = 0;
+    let scale = 10f64.powi(precision as i32);
+
+    // Compare the rounded display value (not the raw value) against the step, so that e.g.
+    // 1023.96 KiB, which would round to "1024.0 KiB", instead bumps up to "1.0 MiB".
+    while (value * scale).round() / scale >= STEP && unit_index < UNITS.len() - 1 {
+        value /= STEP;
+        unit_index += 1;
+    }
+
+    if unit_index == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.*} {}", precision, value, UNITS[unit_index])
+    }
+}
+
+/// Formats `bytes` as a human-readable quantity using binary (KiB/MiB/...) units, e.g.
+/// `"1.2 KiB"`.
+///
+/// This rounds to one decimal place; use [`format_size_with_precision`] for other precisions.
+pub fn format_size(bytes: usize) -> String {
+    format_size_with_precision(bytes, 1)
+}
+
+/// Parses a human-readable byte quantity, as produced by [`format_size`], back into a byte
+/// count.
+///
+/// Accepts an optional binary unit suffix (`B`, `KiB`, `MiB`, ...); a bare number is
+/// interpreted as a count of bytes.
+pub fn parse_size(s: &str) -> Result<usize, ParseSizeError> {
+    let s = s.trim();
+    let split_at = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split_at);
+
+    if number.is_empty() {
+        return Err(ParseSizeError::MissingNumber);
+    }
+    let value: f64 = number.parse().map_err(|_| ParseSizeError::InvalidNumber)?;
+
+    let unit = unit.trim();
+    let unit_index = if unit.is_empty() {
+        0
+    } else {
+        UNITS
+            .iter()
+            .position(|&candidate| candidate.eq_ignore_ascii_case(unit))
+            .ok_or(ParseSizeError::UnknownUnit)?
+    };
+
+    let multiplier = STEP.powi(unit_index as i32);
+    Ok((value * multiplier).round() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_size_keeps_sub_kib_values_as_whole_bytes() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+    }
+
+    #[test]
+    fn format_size_rounds_to_one_decimal_place_by_default() {
+        assert_eq!(format_size(1536), "1.5 KiB");
+    }
+
+    #[test]
+    fn format_size_with_precision_controls_decimal_places() {
+        assert_eq!(format_size_with_precision(1536, 0), "2 KiB");
+        assert_eq!(format_size_with_precision(1536, 2), "1.50 KiB");
+    }
+
+    #[test]
+    fn format_size_bumps_to_the_next_unit_when_rounding_would_hit_the_step() {
+        // 1023.96 KiB rounds to "1024.0 KiB" at one decimal place, so it should bump to MiB.
+        let bytes = (1023.96 * STEP) as usize;
+        assert_eq!(format_size(bytes), "1.0 MiB");
+    }
+
+    #[test]
+    fn format_size_reaches_the_largest_unit() {
+        let bytes = (STEP.powi(6)) as usize; // 1 EiB
+        assert_eq!(format_size(bytes), "1.0 EiB");
+    }
+
+    #[test]
+    fn parse_size_round_trips_a_bare_byte_count() {
+        assert_eq!(parse_size("512").unwrap(), 512);
+        assert_eq!(parse_size("512 B").unwrap(), 512);
+    }
+
+    #[test]
+    fn parse_size_round_trips_formatted_output() {
+        let bytes = 1536;
+        let formatted = format_size(bytes);
+        assert_eq!(parse_size(&formatted).unwrap(), bytes);
+    }
+
+    #[test]
+    fn parse_size_is_case_insensitive_about_the_unit() {
+        assert_eq!(parse_size("1 kib").unwrap(), 1024);
+        assert_eq!(parse_size("1 KIB").unwrap(), 1024);
+    }
+
+    #[test]
+    fn parse_size_trims_surrounding_whitespace() {
+        assert_eq!(parse_size("  1 KiB  ").unwrap(), 1024);
+    }
+
+    #[test]
+    fn parse_size_rejects_a_missing_number() {
+        assert_eq!(parse_size("KiB"), Err(ParseSizeError::MissingNumber));
+        assert_eq!(parse_size(""), Err(ParseSizeError::MissingNumber));
+    }
+
+    #[test]
+    fn parse_size_rejects_an_invalid_number() {
+        assert_eq!(parse_size("1.2.3 KiB"), Err(ParseSizeError::InvalidNumber));
+    }
+
+    #[test]
+    fn parse_size_rejects_an_unknown_unit() {
+        assert_eq!(parse_size("5 QiB"), Err(ParseSizeError::UnknownUnit));
+    }
+}
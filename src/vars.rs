@@ -1,10 +1,332 @@
 //! TI variable data.
 
-pub trait Payload {
+use std::fmt;
+
+use crate::size;
+
+pub trait Payload: Sized {
+    /// The file extension conventionally used for a file wrapping this variable type
+    /// (e.g. `"8xp"` for a program).
     const FILE_EXTENSION: &'static str;
+
+    /// The one-byte type identifier the calculator uses to distinguish this variable type
+    /// from others of the same name.
+    const TYPE_ID: u8;
+
+    /// Parses the payload from its on-calculator byte representation.
+    fn from_bytes(data: &[u8]) -> Result<Self, ReadError>;
+
+    /// Serializes the payload to its on-calculator byte representation.
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+/// Errors encountered while parsing a [`File`] or [`Variable`] from raw bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadError {
+    /// The data does not begin with the expected file signature.
+    InvalidSignature,
+    /// The declared variable length exceeds the actual remaining data.
+    InvalidVariableLength,
+    /// The data contains bytes beyond what the header and variable length describe.
+    TrailingBytes,
+    /// The stored checksum does not match the checksum computed from the variable data.
+    InvalidChecksum,
+    /// A payload is not the size its format requires, or a length-prefixed payload declares
+    /// more data than is actually available.
+    InvalidPayloadLength,
+    /// The variable's stored type ID does not match the payload type it is being read as.
+    InvalidTypeId,
+    /// The variable's two redundant data-length fields disagree with each other.
+    MismatchedLengthFields,
+    /// The data ends before a complete file, variable header, or payload header could be read.
+    UnexpectedEof,
+}
+
+/// A TI real number, stored in the calculator's 9-byte floating-point format: one flags byte,
+/// one exponent byte, and 7 bytes of packed binary-coded-decimal mantissa.
+pub struct Real {
+    flags: u8,
+    exponent: u8,
+    mantissa: [u8; Real::MANTISSA_SIZE],
+}
+
+impl Real {
+    const MANTISSA_SIZE: usize = 7;
+
+    /// The size in bytes of a real number's on-calculator representation.
+    pub const SIZE: usize = 2 + Self::MANTISSA_SIZE;
+
+    /// Gets the flags byte, which encodes the sign of the real number among other bit flags.
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Gets the exponent byte.
+    pub fn exponent(&self) -> u8 {
+        self.exponent
+    }
+
+    /// Gets the 7-byte packed binary-coded-decimal mantissa.
+    pub fn mantissa(&self) -> &[u8; Self::MANTISSA_SIZE] {
+        &self.mantissa
+    }
 }
 
-pub struct Real {}
+impl Payload for Real {
+    const FILE_EXTENSION: &'static str = "8xn";
+    const TYPE_ID: u8 = 0x00;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ReadError> {
+        if data.len() != Self::SIZE {
+            return Err(ReadError::InvalidPayloadLength);
+        }
+        let mut mantissa = [0u8; Self::MANTISSA_SIZE];
+        mantissa.copy_from_slice(&data[2..Self::SIZE]);
+        Ok(Real {
+            flags: data[0],
+            exponent: data[1],
+            mantissa,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::SIZE);
+        bytes.push(self.flags);
+        bytes.push(self.exponent);
+        bytes.extend(self.mantissa.iter());
+        bytes
+    }
+}
+
+/// A list of [`Real`] numbers: a 2-byte little-endian element count followed by that many
+/// 9-byte reals.
+pub struct RealList {
+    elements: Vec<Real>,
+}
+
+impl RealList {
+    /// Gets the reals making up the list.
+    pub fn elements(&self) -> &[Real] {
+        &self.elements
+    }
+}
+
+impl Payload for RealList {
+    const FILE_EXTENSION: &'static str = "8xl";
+    const TYPE_ID: u8 = 0x01;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ReadError> {
+        if data.len() < 2 {
+            return Err(ReadError::UnexpectedEof);
+        }
+        let mut count_bytes = [0u8; 2];
+        count_bytes.copy_from_slice(&data[..2]);
+        let count = u16::from_le_bytes(count_bytes) as usize;
+
+        // Bound-check the declared count before allocating or indexing, the same as the
+        // length-prefixed fields in `File::from_bytes`.
+        let required = count
+            .checked_mul(Real::SIZE)
+            .and_then(|n| n.checked_add(2))
+            .ok_or(ReadError::InvalidPayloadLength)?;
+        if required > data.len() {
+            return Err(ReadError::InvalidPayloadLength);
+        }
+
+        let mut elements = Vec::with_capacity(count);
+        for i in 0..count {
+            let start = 2 + i * Real::SIZE;
+            elements.push(Real::from_bytes(&data[start..start + Real::SIZE])?);
+        }
+        Ok(RealList { elements })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.elements.len() * Real::SIZE);
+        bytes.extend((self.elements.len() as u16).to_le_bytes());
+        for real in &self.elements {
+            bytes.extend(real.to_bytes());
+        }
+        bytes
+    }
+}
+
+/// A matrix of [`Real`] numbers: a rows byte and a columns byte, followed by `rows * columns`
+/// 9-byte reals in row-major order.
+pub struct Matrix {
+    rows: u8,
+    columns: u8,
+    elements: Vec<Real>,
+}
+
+impl Matrix {
+    /// Gets the number of rows in the matrix.
+    pub fn rows(&self) -> u8 {
+        self.rows
+    }
+
+    /// Gets the number of columns in the matrix.
+    pub fn columns(&self) -> u8 {
+        self.columns
+    }
+
+    /// Gets the elements of the matrix in row-major order.
+    pub fn elements(&self) -> &[Real] {
+        &self.elements
+    }
+}
+
+impl Payload for Matrix {
+    const FILE_EXTENSION: &'static str = "8xm";
+    const TYPE_ID: u8 = 0x02;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ReadError> {
+        if data.len() < 2 {
+            return Err(ReadError::UnexpectedEof);
+        }
+        let rows = data[0];
+        let columns = data[1];
+
+        let count = rows as usize * columns as usize;
+        let required = count
+            .checked_mul(Real::SIZE)
+            .and_then(|n| n.checked_add(2))
+            .ok_or(ReadError::InvalidPayloadLength)?;
+        if required > data.len() {
+            return Err(ReadError::InvalidPayloadLength);
+        }
+
+        let mut elements = Vec::with_capacity(count);
+        for i in 0..count {
+            let start = 2 + i * Real::SIZE;
+            elements.push(Real::from_bytes(&data[start..start + Real::SIZE])?);
+        }
+        Ok(Matrix {
+            rows,
+            columns,
+            elements,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.elements.len() * Real::SIZE);
+        bytes.push(self.rows);
+        bytes.push(self.columns);
+        for real in &self.elements {
+            bytes.extend(real.to_bytes());
+        }
+        bytes
+    }
+}
+
+/// A tokenized TI-BASIC program.
+pub struct Program {
+    tokens: Vec<u8>,
+}
+
+impl Program {
+    /// Gets the raw tokenized program data.
+    pub fn tokens(&self) -> &[u8] {
+        &self.tokens
+    }
+}
+
+impl Payload for Program {
+    const FILE_EXTENSION: &'static str = "8xp";
+    const TYPE_ID: u8 = 0x05;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ReadError> {
+        Ok(Program {
+            tokens: data.to_vec(),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.tokens.clone()
+    }
+}
+
+/// A tokenized TI-BASIC program that is locked against editing on the calculator.
+pub struct ProtectedProgram {
+    tokens: Vec<u8>,
+}
+
+impl ProtectedProgram {
+    /// Gets the raw tokenized program data.
+    pub fn tokens(&self) -> &[u8] {
+        &self.tokens
+    }
+}
+
+impl Payload for ProtectedProgram {
+    const FILE_EXTENSION: &'static str = "8xp";
+    const TYPE_ID: u8 = 0x06;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ReadError> {
+        Ok(ProtectedProgram {
+            tokens: data.to_vec(),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.tokens.clone()
+    }
+}
+
+/// An application variable: an opaque block of data owned by a calculator application
+/// rather than the OS.
+pub struct AppVar {
+    data: Vec<u8>,
+}
+
+impl AppVar {
+    /// Gets the raw application-defined data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Payload for AppVar {
+    const FILE_EXTENSION: &'static str = "8xv";
+    const TYPE_ID: u8 = 0x15;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ReadError> {
+        Ok(AppVar {
+            data: data.to_vec(),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.data.clone()
+    }
+}
+
+/// A TI string, stored as tokenized character data.
+pub struct TiString {
+    tokens: Vec<u8>,
+}
+
+impl TiString {
+    /// Gets the raw tokenized string data.
+    pub fn tokens(&self) -> &[u8] {
+        &self.tokens
+    }
+}
+
+impl Payload for TiString {
+    const FILE_EXTENSION: &'static str = "8xs";
+    const TYPE_ID: u8 = 0x04;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ReadError> {
+        Ok(TiString {
+            tokens: data.to_vec(),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.tokens.clone()
+    }
+}
 
 /// Options for how the program should treat perceived errors in reading raw data.
 #[derive(Clone, Copy)]
@@ -27,6 +349,14 @@ impl ReadMode {
     pub unsafe fn ignore() -> Self {
         ReadMode(2)
     }
+
+    fn is_error(self) -> bool {
+        self.0 == 0
+    }
+
+    fn is_fix(self) -> bool {
+        self.0 == 1
+    }
 }
 
 impl Default for ReadMode {
@@ -35,16 +365,239 @@ impl Default for ReadMode {
     }
 }
 
+/// Error-handling options for reading in a variable.
+///
+/// See [`ReadMode`] for the different modes.
 #[derive(Clone, Default)]
-pub struct VariableReadOptions;
+pub struct VariableReadOptions {
+    /// Governs validation of the variable header: the stored type ID must match the payload
+    /// type it is being read as, and the two redundant data-length fields must agree.
+    pub header: ReadMode,
+}
+
+const VARIABLE_LENGTH_FIELD_SIZE: usize = 0x02;
+const VARIABLE_TYPE_ID_SIZE: usize = 0x01;
+const VARIABLE_NAME_SIZE: usize = 0x08;
+const VARIABLE_VERSION_SIZE: usize = 0x01;
+const VARIABLE_ARCHIVE_FLAG_SIZE: usize = 0x01;
+
+/// The token used for the Greek letter theta, the one non-alphanumeric character the
+/// calculator allows in a variable name.
+const THETA_TOKEN: u8 = 0x5B;
+
+/// Error returned when a variable name does not conform to the calculator's naming rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameError {
+    /// The name is longer than [`Variable::NAME_SIZE`] bytes.
+    TooLong,
+    /// The name contains a character the calculator does not allow in a variable name, or
+    /// starts with a digit.
+    InvalidToken,
+}
 
+/// A named, typed value stored on a TI calculator, wrapped in its header metadata.
+///
+/// A variable is identified by its [`Variable::name`] and [`Variable::type_id`] (determined by
+/// its payload type `T`), and carries a format [`Variable::version`] and an
+/// [`Variable::is_archived`] flag alongside its payload.
 pub struct Variable<T: Payload> {
+    name: [u8; VARIABLE_NAME_SIZE],
+    version: u8,
+    archived: bool,
+    type_id: u8,
     payload: T,
 }
 
 impl<T: Payload> Variable<T> {
+    pub const LENGTH_FIELD_SIZE: usize = VARIABLE_LENGTH_FIELD_SIZE;
+    pub const TYPE_ID_SIZE: usize = VARIABLE_TYPE_ID_SIZE;
+    pub const NAME_SIZE: usize = VARIABLE_NAME_SIZE;
+    pub const VERSION_SIZE: usize = VARIABLE_VERSION_SIZE;
+    pub const ARCHIVE_FLAG_SIZE: usize = VARIABLE_ARCHIVE_FLAG_SIZE;
+
+    /// The size in bytes of the variable header, i.e. everything before the payload: the
+    /// data length stored twice, the type ID, the name, the version, and the archive flag.
+    pub const HEADER_SIZE: usize = Self::LENGTH_FIELD_SIZE * 2
+        + Self::TYPE_ID_SIZE
+        + Self::NAME_SIZE
+        + Self::VERSION_SIZE
+        + Self::ARCHIVE_FLAG_SIZE;
+
+    /// Creates a new, unarchived variable named `name` wrapping `payload`.
+    pub fn new(name: &str, payload: T) -> Result<Self, NameError> {
+        let mut variable = Variable {
+            name: [0; VARIABLE_NAME_SIZE],
+            version: 0,
+            archived: false,
+            type_id: T::TYPE_ID,
+            payload,
+        };
+        variable.set_name(name)?;
+        Ok(variable)
+    }
+
+    /// Parses a variable, including its header, from the raw bytes of the variable region
+    /// of a [`File`].
+    pub fn from_bytes(data: &[u8], opts: &VariableReadOptions) -> Result<Self, ReadError> {
+        if data.len() < Self::HEADER_SIZE {
+            return Err(ReadError::UnexpectedEof);
+        }
+
+        let mut declared_length_bytes = [0u8; VARIABLE_LENGTH_FIELD_SIZE];
+        declared_length_bytes.copy_from_slice(&data[0..Self::LENGTH_FIELD_SIZE]);
+        let mut declared_length = u16::from_le_bytes(declared_length_bytes) as usize;
+
+        let mut declared_length_bytes_2 = [0u8; VARIABLE_LENGTH_FIELD_SIZE];
+        declared_length_bytes_2
+            .copy_from_slice(&data[Self::LENGTH_FIELD_SIZE..Self::LENGTH_FIELD_SIZE * 2]);
+        let declared_length_2 = u16::from_le_bytes(declared_length_bytes_2) as usize;
+        if declared_length != declared_length_2 {
+            if opts.header.is_error() {
+                return Err(ReadError::MismatchedLengthFields);
+            } else if opts.header.is_fix() {
+                declared_length = declared_length.min(declared_length_2);
+            }
+            // ignore mode: keep trusting the first field, however inconsistent.
+        }
+
+        let type_id_offset = Self::LENGTH_FIELD_SIZE * 2;
+        let name_offset = type_id_offset + Self::TYPE_ID_SIZE;
+        let version_offset = name_offset + Self::NAME_SIZE;
+        let archive_offset = version_offset + Self::VERSION_SIZE;
+        let payload_offset = archive_offset + Self::ARCHIVE_FLAG_SIZE;
+
+        let mut type_id = data[type_id_offset];
+        if type_id != T::TYPE_ID {
+            if opts.header.is_error() {
+                return Err(ReadError::InvalidTypeId);
+            } else if opts.header.is_fix() {
+                type_id = T::TYPE_ID;
+            }
+            // ignore mode: keep whatever type ID was stored, however invalid.
+        }
+
+        // Bound-check the declared payload length before slicing, the same as the file-level
+        // variable length in `File::from_bytes`.
+        let available_for_payload = data.len() - payload_offset;
+        if declared_length > available_for_payload {
+            return Err(ReadError::InvalidPayloadLength);
+        }
+
+        let mut name = [0u8; VARIABLE_NAME_SIZE];
+        name.copy_from_slice(&data[name_offset..name_offset + Self::NAME_SIZE]);
+        let version = data[version_offset];
+        let archived = data[archive_offset] != 0;
+
+        let payload = T::from_bytes(&data[payload_offset..payload_offset + declared_length])?;
+
+        Ok(Variable {
+            name,
+            version,
+            archived,
+            type_id,
+            payload,
+        })
+    }
+
+    /// Serializes the variable, including its header, to its on-calculator byte representation.
     pub fn bytes(&self) -> Vec<u8> {
-        todo!()
+        let payload_bytes = self.payload.to_bytes();
+        let length = payload_bytes.len() as u16;
+
+        let mut bytes = Vec::with_capacity(Self::HEADER_SIZE + payload_bytes.len());
+        bytes.extend(length.to_le_bytes());
+        bytes.extend(length.to_le_bytes());
+        bytes.push(self.type_id);
+        bytes.extend(self.name.iter());
+        bytes.push(self.version);
+        bytes.push(self.archived as u8);
+        bytes.extend(payload_bytes);
+        bytes
+    }
+
+    fn name_length(&self) -> usize {
+        self.name
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(Self::NAME_SIZE)
+    }
+
+    /// Gets the name of the variable, as displayed on the calculator.
+    pub fn name(&self) -> Result<String, std::string::FromUtf8Error> {
+        String::from_utf8(self.name[..self.name_length()].to_vec())
+    }
+
+    fn is_valid_name_byte(byte: u8, index: usize) -> bool {
+        match byte {
+            b'A'..=b'Z' => true,
+            b'0'..=b'9' => index > 0,
+            THETA_TOKEN => true,
+            _ => false,
+        }
+    }
+
+    /// Sets the name of the variable.
+    ///
+    /// Names may be at most [`Variable::NAME_SIZE`] bytes, must consist of the letters `A`
+    /// through `Z`, the digits `0` through `9`, or the theta token (the byte `0x5B`, i.e. `[`;
+    /// the UTF-8 Greek letter `θ` is rejected), and may not start with a digit, matching the
+    /// naming rules the calculator itself enforces.
+    pub fn set_name(&mut self, name: &str) -> Result<(), NameError> {
+        let bytes = name.as_bytes();
+        if bytes.len() > Self::NAME_SIZE {
+            return Err(NameError::TooLong);
+        }
+        for (i, &b) in bytes.iter().enumerate() {
+            if !Self::is_valid_name_byte(b, i) {
+                return Err(NameError::InvalidToken);
+            }
+        }
+
+        let mut padded = [0u8; VARIABLE_NAME_SIZE];
+        padded[..bytes.len()].copy_from_slice(bytes);
+        self.name = padded;
+        Ok(())
+    }
+
+    /// Gets the one-byte type identifier stored for this variable.
+    ///
+    /// This matches [`Payload::TYPE_ID`] for `T` unless the variable was parsed with a
+    /// [`ReadMode`] permissive enough to let a mismatched stored type ID through.
+    pub fn type_id(&self) -> u8 {
+        self.type_id
+    }
+
+    /// Gets the format version of the variable.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Gets whether the variable is archived, i.e. stored in archive memory rather than RAM.
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    /// Sets whether the variable is archived.
+    pub fn set_archived(&mut self, archived: bool) {
+        self.archived = archived;
+    }
+
+    /// Gets the variable's payload.
+    pub fn payload(&self) -> &T {
+        &self.payload
+    }
+
+    /// Gets the variable's payload for mutation.
+    pub fn payload_mut(&mut self) -> &mut T {
+        &mut self.payload
+    }
+}
+
+impl<T: Payload> fmt::Display for Variable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name().unwrap_or_default();
+        let total_size = Self::HEADER_SIZE + self.payload.to_bytes().len();
+        write!(f, "{} ({})", name, size::format_size(total_size))
     }
 }
 
@@ -64,6 +617,136 @@ const FILE_COMMENT_SIZE: usize = 0x2A;
 const FILE_VARIABLE_LENGTH_SIZE: usize = 0x02;
 const FILE_CHECKSUM_SIZE: usize = 0x02;
 
+/// The signature written by the calculator: the string `**TI83F*` followed by the bytes
+/// `0x1A`, `0x0A`, and `0x00`.
+const CANONICAL_SIGNATURE: [u8; FILE_SIGNATURE_SIZE] = *b"**TI83F*\x1A\x0A\x00";
+
+/// How a [`Comment`]'s unused capacity is represented in its raw bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    /// The string ends with a single `0x00` byte; bytes beyond it are left unspecified.
+    ZeroTerminated,
+    /// The string is right-padded with space characters to fill the region.
+    Padded,
+}
+
+/// A fixed-capacity string backed by an inline `[u8; 42]` buffer, matching the comment
+/// region of a [`File`].
+///
+/// A comment can be either zero-terminated or right-padded with spaces; see [`Termination`].
+/// Unlike the raw-byte helpers this replaces, a `Comment` cannot overflow its region: extra
+/// bytes passed to [`Comment::push_str`] are simply not copied in.
+#[derive(Clone, Copy)]
+pub struct Comment {
+    bytes: [u8; FILE_COMMENT_SIZE],
+    len: usize,
+    termination: Termination,
+}
+
+impl Comment {
+    /// The number of bytes a comment can hold.
+    pub const CAPACITY: usize = FILE_COMMENT_SIZE;
+
+    /// Creates an empty, zero-terminated comment.
+    pub fn new() -> Self {
+        Comment {
+            bytes: [0; Self::CAPACITY],
+            len: 0,
+            termination: Termination::ZeroTerminated,
+        }
+    }
+
+    /// Interprets a raw comment region as a `Comment`, inferring its [`Termination`] from
+    /// whether the bytes contain a null terminator.
+    pub fn from_raw(raw: [u8; Self::CAPACITY]) -> Self {
+        const SPACE: u8 = b' ';
+        let (len, termination) = match raw.iter().position(|&b| b == 0) {
+            Some(pos) => (pos, Termination::ZeroTerminated),
+            None => {
+                let ending_spaces = raw.iter().rev().take_while(|&&b| b == SPACE).count();
+                (Self::CAPACITY - ending_spaces, Termination::Padded)
+            }
+        };
+        Comment {
+            bytes: raw,
+            len,
+            termination,
+        }
+    }
+
+    /// Gets the raw bytes of the comment region, as stored in a file.
+    pub fn to_raw(&self) -> [u8; Self::CAPACITY] {
+        self.bytes
+    }
+
+    /// Gets the comment as a string, excluding its padding or terminator.
+    pub fn as_str(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(&self.bytes[..self.len])
+    }
+
+    /// Gets the length in bytes of the comment, excluding its padding or terminator.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Gets whether the comment is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets how the comment's unused capacity is currently represented.
+    pub fn termination(&self) -> Termination {
+        self.termination
+    }
+
+    /// Sets how the comment's unused capacity should be represented, and rewrites the
+    /// trailing bytes of the region accordingly.
+    pub fn set_termination(&mut self, termination: Termination) {
+        self.termination = termination;
+        self.fill_padding();
+    }
+
+    fn fill_padding(&mut self) {
+        let pad_byte = match self.termination {
+            Termination::ZeroTerminated => 0,
+            Termination::Padded => b' ',
+        };
+        for byte in &mut self.bytes[self.len..] {
+            *byte = pad_byte;
+        }
+    }
+
+    /// Appends as much of `s` as fits within [`Comment::CAPACITY`], truncating the rest.
+    ///
+    /// Truncation always falls on a `char` boundary, so the appended bytes never split a
+    /// multi-byte character and [`Comment::as_str`] remains valid UTF-8.
+    ///
+    /// Returns the number of bytes actually appended.
+    pub fn push_str(&mut self, s: &str) -> usize {
+        let available = Self::CAPACITY - self.len;
+        let mut taken = s.len().min(available);
+        while taken > 0 && !s.is_char_boundary(taken) {
+            taken -= 1;
+        }
+        self.bytes[self.len..self.len + taken].copy_from_slice(&s.as_bytes()[..taken]);
+        self.len += taken;
+        self.fill_padding();
+        taken
+    }
+
+    /// Empties the comment.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.fill_padding();
+    }
+}
+
+impl Default for Comment {
+    fn default() -> Self {
+        Comment::new()
+    }
+}
+
 /// Data representation of a file exported from a TI calculator.
 ///
 /// Files are wrappers around variables and other data, and they include metadata
@@ -71,7 +754,7 @@ const FILE_CHECKSUM_SIZE: usize = 0x02;
 /// optional comment.
 pub struct File<T: Payload> {
     signature: [u8; FILE_SIGNATURE_SIZE],
-    comment: [u8; FILE_COMMENT_SIZE],
+    comment: Comment,
     variable_length: [u8; FILE_VARIABLE_LENGTH_SIZE],
     variable: Variable<T>,
     checksum: [u8; FILE_CHECKSUM_SIZE],
@@ -109,13 +792,100 @@ impl<T: Payload> File<T> {
     pub fn bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(self.size());
         bytes.extend(self.signature.iter());
-        bytes.extend(self.comment.iter());
+        bytes.extend(self.comment.to_raw().iter());
         bytes.extend(self.variable_length.iter());
         bytes.extend(self.variable.bytes().iter());
         bytes.extend(self.checksum.iter());
         bytes
     }
 
+    /// Parses a file from its raw on-device representation, according to `opts`.
+    ///
+    /// The layout is read in order: the [`File::SIGNATURE_SIZE`]-byte signature, the
+    /// [`File::COMMENT_SIZE`]-byte comment, the [`File::VARIABLE_LENGTH_SIZE`]-byte little-endian
+    /// variable length, the variable region itself, and finally the [`File::CHECKSUM_SIZE`]-byte
+    /// checksum. Each field is validated according to the [`ReadMode`] configured for it in
+    /// `opts`; see [`FileReadOptions`].
+    ///
+    /// The declared variable length is always bounds-checked against the actual size of `data`
+    /// before it is used to slice out the variable region, regardless of `opts`, since honoring
+    /// an over-large length verbatim would require reading past the end of `data`.
+    pub fn from_bytes(data: &[u8], opts: &FileReadOptions) -> Result<Self, ReadError> {
+        if data.len() < Self::HEADER_SIZE + Self::CHECKSUM_SIZE {
+            return Err(ReadError::UnexpectedEof);
+        }
+
+        let mut signature = [0u8; FILE_SIGNATURE_SIZE];
+        signature.copy_from_slice(&data[Self::SIGNATURE_OFFSET..Self::COMMENT_OFFSET]);
+        if signature != CANONICAL_SIGNATURE {
+            if opts.signature.is_error() {
+                return Err(ReadError::InvalidSignature);
+            } else if opts.signature.is_fix() {
+                signature = CANONICAL_SIGNATURE;
+            }
+            // ignore mode: keep whatever bytes were read, however invalid.
+        }
+
+        let mut comment_raw = [0u8; FILE_COMMENT_SIZE];
+        comment_raw.copy_from_slice(&data[Self::COMMENT_OFFSET..Self::VARIABLE_LENGTH_OFFSET]);
+        let comment = Comment::from_raw(comment_raw);
+
+        let mut variable_length_raw = [0u8; FILE_VARIABLE_LENGTH_SIZE];
+        variable_length_raw
+            .copy_from_slice(&data[Self::VARIABLE_LENGTH_OFFSET..Self::VARIABLE_OFFSET]);
+        let declared_variable_length = u16::from_le_bytes(variable_length_raw);
+        let available_for_variable = data.len() - Self::HEADER_SIZE - Self::CHECKSUM_SIZE;
+
+        let variable_length = match (declared_variable_length as usize).cmp(&available_for_variable) {
+            std::cmp::Ordering::Equal => declared_variable_length,
+            std::cmp::Ordering::Greater => {
+                // The header claims more data than actually exists; never trust that far,
+                // even in ignore mode, since honoring it would over-read `data`.
+                if opts.variable_length.is_error() {
+                    return Err(ReadError::InvalidVariableLength);
+                }
+                variable_length_raw = (available_for_variable as u16).to_le_bytes();
+                available_for_variable as u16
+            }
+            std::cmp::Ordering::Less => {
+                if opts.variable_length.is_error() {
+                    return Err(ReadError::TrailingBytes);
+                } else if opts.variable_length.is_fix() {
+                    variable_length_raw = (available_for_variable as u16).to_le_bytes();
+                    available_for_variable as u16
+                } else {
+                    declared_variable_length
+                }
+            }
+        };
+
+        let variable_start = Self::VARIABLE_OFFSET;
+        let variable_end = variable_start + variable_length as usize;
+        let variable = Variable::from_bytes(&data[variable_start..variable_end], &opts.variable)?;
+
+        let mut checksum = [0u8; FILE_CHECKSUM_SIZE];
+        checksum.copy_from_slice(&data[variable_end..variable_end + Self::CHECKSUM_SIZE]);
+
+        let mut file = File {
+            signature,
+            comment,
+            variable_length: variable_length_raw,
+            variable,
+            checksum,
+        };
+
+        if opts.checksum.is_error() {
+            if file.checksum() != file.compute_checksum() {
+                return Err(ReadError::InvalidChecksum);
+            }
+        } else if opts.checksum.is_fix() {
+            file.recompute_checksum();
+        }
+        // ignore mode: keep the stored checksum as-is, even if it is wrong.
+
+        Ok(file)
+    }
+
     /// Gets the "signature," which identifies the data as usable on TI devices.
     ///
     /// This is always the string `**TI83F*` followed by the bytes `0x1A`, `0x0A`, and `0x00`.
@@ -135,129 +905,18 @@ impl<T: Payload> File<T> {
         &mut self.signature
     }
 
-    fn comment_null_terminator_position(&self) -> Option<usize> {
-        self.comment.iter().position(|c| *c == 0)
-    }
-
-    fn comment_ending_spaces(&self) -> usize {
-        const SPACE_AS_NUMBER: u8 = ' ' as u8;
-        self.comment
-            .iter()
-            .rev()
-            .take_while(|c| **c == SPACE_AS_NUMBER)
-            .count()
-    }
-
-    /// Gets the region of data reserved for a comment and parses it as a UTF-8 string.
+    /// Gets the comment stored in the file.
     ///
     /// This data is left empty when generated on the calculator, but other programs
     /// which have modified this region may not necessarily have formatted it in UTF-8.
-    ///
-    /// Internally, the string is either zero-terminated or padded with space characters.
-    /// When the `trim` parameter is set to `true`, the space padding on the right will
-    /// be removed from the result.
-    ///
-    /// Use [`File::comment_raw`] to extract the bytes.
-    pub fn comment(&self, mut trim: bool) -> Result<String, std::string::FromUtf8Error> {
-        let comment = match self.comment_null_terminator_position() {
-            Some(pos) => {
-                trim = false;
-                &self.comment[..pos]
-            }
-            None => &self.comment,
-        };
-        let mut comment = String::from_utf8(comment.to_vec())?;
-        if trim {
-            let trimmed_len = comment.trim_end_matches(' ').len();
-            comment.truncate(trimmed_len);
-        }
-        Ok(comment)
-    }
-
-    /// Gets the size in bytes of the comment in the region of data reserved for it.
-    ///
-    /// Comments can be zero-terminated or padded to the right with spaces. This function
-    /// ignores both when calculating the length.
-    pub fn comment_length(&self) -> usize {
-        match self.comment_null_terminator_position() {
-            Some(null_char_position) => null_char_position,
-            None => Self::COMMENT_SIZE - self.comment_ending_spaces(),
-        }
-    }
-
-    /// Gets whether the comment data is zero-terminated (`true`) or padded (`false`).
-    ///
-    /// If the comment fills the entire space in memory (see [`File::COMMENT_SIZE`]),
-    /// this returns `false`.
-    pub fn is_comment_zero_terminated(&self) -> bool {
-        self.comment.contains(&0)
-    }
-
-    /// Forces the comment region of data to be zero-terminated.
-    ///
-    /// The comment region can end by being padded with spaces, and if this is the case,
-    /// this changes the string to be zero-terminated.
-    pub fn make_comment_zero_terminated(&mut self) {
-        if self.is_comment_zero_terminated() {
-            return;
-        }
-        let ending_spaces = self.comment_ending_spaces();
-        if ending_spaces == 0 {
-            // There is no room at the end for a null terminator.
-            return;
-        }
-        let first_space_index = Self::COMMENT_SIZE - ending_spaces;
-        self.comment[first_space_index] = 0;
-    }
-
-    /// Forces the comment region of data to end with space-character padding.
-    ///
-    /// The comment region can be zero-terminated, and if this is the case, this replaces
-    /// the termination with right-padding made of space characters.
-    pub fn make_comment_padded(&mut self) {
-        let Some(pad_start) = self.comment_null_terminator_position() else { return };
-        const SPACE_AS_NUMBER: u8 = ' ' as u8;
-        for i in pad_start..Self::COMMENT_SIZE {
-            self.comment[i] = SPACE_AS_NUMBER;
-        }
-    }
-
-    /// Stores a UTF-8 string in the region of data reserved for a comment.
-    ///
-    /// If `zero_terminated` is set to `false`, the comment will be padded at the end
-    /// with spaces.
-    ///
-    /// This function will only take as many bytes from the string as will fit
-    /// in the data region; see [`File::COMMENT_SIZE`].
-    pub fn set_comment(&mut self, comment: &str, zero_terminated: bool) {
-        let mut bytes = comment.bytes();
-
-        for i in 0..Self::COMMENT_SIZE {
-            match bytes.next() {
-                Some(b) => self.comment[i] = b,
-                None => {
-                    if zero_terminated {
-                        self.comment[i] = 0;
-                        return;
-                    }
-                    const SPACE_AS_NUMBER: u8 = ' ' as u8;
-                    self.comment[i] = SPACE_AS_NUMBER;
-                }
-            }
-        }
-    }
-
-    /// Gets the raw data from the region reserved for a comment.
-    ///
-    /// The comment is either zero-terminated or padded with spaces.
-    pub fn comment_raw(&self) -> &[u8; FILE_COMMENT_SIZE] {
+    pub fn comment(&self) -> &Comment {
         &self.comment
     }
 
-    /// Gets the raw data from the region reserved for a comment for mutation.
+    /// Gets the comment stored in the file for mutation.
     ///
     /// Changing the comment is safe; the calculator never reads it.
-    pub fn comment_raw_mut(&mut self) -> &mut [u8; FILE_COMMENT_SIZE] {
+    pub fn comment_mut(&mut self) -> &mut Comment {
         &mut self.comment
     }
 
@@ -325,4 +984,472 @@ impl<T: Payload> File<T> {
     pub unsafe fn checksum_raw_mut(&mut self) -> &mut [u8; FILE_CHECKSUM_SIZE] {
         &mut self.checksum
     }
+
+    /// Computes the checksum that corresponds to the current variable data.
+    ///
+    /// This is the lower 16 bits of the sum of every byte in the variable section of the data,
+    /// regardless of what is currently stored in [`File::checksum`].
+    pub fn compute_checksum(&self) -> u16 {
+        self.variable
+            .bytes()
+            .iter()
+            .fold(0u16, |sum, &byte| sum.wrapping_add(byte as u16))
+    }
+
+    /// Gets whether the stored checksum matches [`File::compute_checksum`].
+    pub fn verify_checksum(&self) -> bool {
+        self.checksum() == self.compute_checksum()
+    }
+
+    /// Recomputes the checksum from the current variable data and overwrites the stored value.
+    ///
+    /// Call this after mutating the variable and before calling [`File::bytes`],
+    /// or the calculator will reject the file.
+    pub fn recompute_checksum(&mut self) {
+        self.checksum = self.compute_checksum().to_le_bytes();
+    }
+}
+
+impl<T: Payload> fmt::Display for File<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.variable.name().unwrap_or_default();
+        write!(f, "{} ({})", name, size::format_size(self.size()))
+    }
+}
+
+#[cfg(test)]
+mod file_read_tests {
+    use super::*;
+
+    pub(super) fn expect_err<V>(result: Result<V, ReadError>) -> ReadError {
+        match result {
+            Ok(_) => panic!("expected an error, but parsing succeeded"),
+            Err(error) => error,
+        }
+    }
+
+    pub(super) fn sample_variable_bytes() -> Vec<u8> {
+        let real = Real::from_bytes(&[0; Real::SIZE]).unwrap();
+        Variable::new("A", real).unwrap().bytes()
+    }
+
+    /// Builds a byte buffer that `File::<Real>::from_bytes` accepts outright, with a correct
+    /// checksum, by constructing it once under a fixing [`FileReadOptions`] and re-serializing.
+    pub(super) fn valid_file_bytes() -> Vec<u8> {
+        let variable_bytes = sample_variable_bytes();
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&CANONICAL_SIGNATURE);
+        bytes.extend_from_slice(&[0u8; FILE_COMMENT_SIZE]);
+        bytes.extend_from_slice(&(variable_bytes.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&variable_bytes);
+        bytes.extend_from_slice(&[0u8; FILE_CHECKSUM_SIZE]);
+
+        let opts = FileReadOptions {
+            checksum: ReadMode::fix(),
+            ..Default::default()
+        };
+        let file: File<Real> = File::from_bytes(&bytes, &opts).unwrap();
+        file.bytes()
+    }
+
+    #[test]
+    fn parses_a_well_formed_file() {
+        let bytes = valid_file_bytes();
+        let file: File<Real> = File::from_bytes(&bytes, &FileReadOptions::default()).unwrap();
+        assert!(file.verify_checksum());
+    }
+
+    #[test]
+    fn rejects_bad_signature_in_error_mode() {
+        let mut bytes = valid_file_bytes();
+        bytes[0] = b'X';
+        let result: Result<File<Real>, ReadError> =
+            File::from_bytes(&bytes, &FileReadOptions::default());
+        assert_eq!(expect_err(result), ReadError::InvalidSignature);
+    }
+
+    #[test]
+    fn fixes_bad_signature_in_fix_mode() {
+        let mut bytes = valid_file_bytes();
+        bytes[0] = b'X';
+        let opts = FileReadOptions {
+            signature: ReadMode::fix(),
+            ..Default::default()
+        };
+        let file: File<Real> = File::from_bytes(&bytes, &opts).unwrap();
+        assert_eq!(file.signature(), &CANONICAL_SIGNATURE);
+    }
+
+    #[test]
+    fn keeps_bad_signature_in_ignore_mode() {
+        let mut bytes = valid_file_bytes();
+        bytes[0] = b'X';
+        let opts = FileReadOptions {
+            signature: unsafe { ReadMode::ignore() },
+            ..Default::default()
+        };
+        let file: File<Real> = File::from_bytes(&bytes, &opts).unwrap();
+        assert_eq!(file.signature()[0], b'X');
+    }
+
+    #[test]
+    fn clamps_oversized_variable_length_even_in_ignore_mode() {
+        let mut bytes = valid_file_bytes();
+        let original_length = File::<Real>::VARIABLE_LENGTH_OFFSET;
+        let declared: u16 = bytes.len() as u16 + 100;
+        bytes[original_length..original_length + 2].copy_from_slice(&declared.to_le_bytes());
+
+        let opts = FileReadOptions {
+            variable_length: unsafe { ReadMode::ignore() },
+            ..Default::default()
+        };
+        // Clamped to what's actually available rather than trusted verbatim or rejected.
+        let file: File<Real> = File::from_bytes(&bytes, &opts).unwrap();
+        assert!(file.variable_length() as usize <= bytes.len());
+    }
+
+    #[test]
+    fn rejects_trailing_bytes_in_error_mode() {
+        let mut bytes = valid_file_bytes();
+        let offset = File::<Real>::VARIABLE_LENGTH_OFFSET;
+        let actual_length = u16::from_le_bytes([bytes[offset], bytes[offset + 1]]);
+        bytes[offset..offset + 2].copy_from_slice(&(actual_length - 1).to_le_bytes());
+
+        let result: Result<File<Real>, ReadError> =
+            File::from_bytes(&bytes, &FileReadOptions::default());
+        assert_eq!(expect_err(result), ReadError::TrailingBytes);
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let bytes = valid_file_bytes();
+        let result: Result<File<Real>, ReadError> =
+            File::from_bytes(&bytes[..bytes.len() / 2], &FileReadOptions::default());
+        assert_eq!(expect_err(result), ReadError::UnexpectedEof);
+    }
+}
+
+#[cfg(test)]
+mod checksum_tests {
+    use super::file_read_tests::{expect_err, valid_file_bytes};
+    use super::*;
+
+    #[test]
+    fn compute_checksum_matches_verify_checksum() {
+        let bytes = valid_file_bytes();
+        let file: File<Real> = File::from_bytes(&bytes, &FileReadOptions::default()).unwrap();
+        assert!(file.verify_checksum());
+        assert_eq!(file.checksum(), file.compute_checksum());
+    }
+
+    #[test]
+    fn rejects_bad_checksum_in_error_mode() {
+        let mut bytes = valid_file_bytes();
+        let offset = bytes.len() - File::<Real>::CHECKSUM_SIZE;
+        bytes[offset] ^= 0xFF;
+        let result: Result<File<Real>, ReadError> =
+            File::from_bytes(&bytes, &FileReadOptions::default());
+        assert_eq!(expect_err(result), ReadError::InvalidChecksum);
+    }
+
+    #[test]
+    fn fixes_bad_checksum_in_fix_mode() {
+        let mut bytes = valid_file_bytes();
+        let offset = bytes.len() - File::<Real>::CHECKSUM_SIZE;
+        bytes[offset] ^= 0xFF;
+        let opts = FileReadOptions {
+            checksum: ReadMode::fix(),
+            ..Default::default()
+        };
+        let file: File<Real> = File::from_bytes(&bytes, &opts).unwrap();
+        assert!(file.verify_checksum());
+    }
+
+    #[test]
+    fn keeps_bad_checksum_in_ignore_mode() {
+        let mut bytes = valid_file_bytes();
+        let offset = bytes.len() - File::<Real>::CHECKSUM_SIZE;
+        bytes[offset] ^= 0xFF;
+        let opts = FileReadOptions {
+            checksum: unsafe { ReadMode::ignore() },
+            ..Default::default()
+        };
+        let file: File<Real> = File::from_bytes(&bytes, &opts).unwrap();
+        assert!(!file.verify_checksum());
+    }
+
+    #[test]
+    fn recompute_checksum_fixes_a_mutated_file() {
+        let bytes = valid_file_bytes();
+        let mut file: File<Real> = File::from_bytes(&bytes, &FileReadOptions::default()).unwrap();
+        unsafe {
+            file.checksum_raw_mut()[0] ^= 0xFF;
+        }
+        assert!(!file.verify_checksum());
+        file.recompute_checksum();
+        assert!(file.verify_checksum());
+    }
+}
+
+#[cfg(test)]
+mod variable_tests {
+    use super::*;
+
+    fn real_bytes() -> [u8; Real::SIZE] {
+        let mut bytes = [0u8; Real::SIZE];
+        bytes[0] = 0x00; // flags
+        bytes[1] = 0x80; // exponent
+        bytes
+    }
+
+    #[test]
+    fn real_round_trips_through_bytes() {
+        let real = Real::from_bytes(&real_bytes()).unwrap();
+        assert_eq!(real.flags(), 0x00);
+        assert_eq!(real.exponent(), 0x80);
+        assert_eq!(real.to_bytes(), real_bytes());
+    }
+
+    #[test]
+    fn real_list_round_trips_through_bytes() {
+        let list = RealList::from_bytes(&{
+            let mut bytes = Vec::new();
+            bytes.extend(2u16.to_le_bytes());
+            bytes.extend(real_bytes());
+            bytes.extend(real_bytes());
+            bytes
+        })
+        .unwrap();
+        assert_eq!(list.elements().len(), 2);
+    }
+
+    #[test]
+    fn real_list_rejects_an_oversized_declared_count() {
+        let mut bytes = Vec::new();
+        bytes.extend(u16::MAX.to_le_bytes());
+        bytes.extend(real_bytes());
+        let result = RealList::from_bytes(&bytes);
+        assert_eq!(result.err(), Some(ReadError::InvalidPayloadLength));
+    }
+
+    #[test]
+    fn matrix_round_trips_through_bytes() {
+        let mut bytes = Vec::new();
+        bytes.push(2); // rows
+        bytes.push(3); // columns
+        for _ in 0..6 {
+            bytes.extend(real_bytes());
+        }
+        let matrix = Matrix::from_bytes(&bytes).unwrap();
+        assert_eq!(matrix.rows(), 2);
+        assert_eq!(matrix.columns(), 3);
+        assert_eq!(matrix.elements().len(), 6);
+    }
+
+    #[test]
+    fn matrix_rejects_a_declared_size_past_the_end_of_the_data() {
+        let mut bytes = Vec::new();
+        bytes.push(2);
+        bytes.push(3);
+        bytes.extend(real_bytes()); // only one real, not the declared six
+        let result = Matrix::from_bytes(&bytes);
+        assert_eq!(result.err(), Some(ReadError::InvalidPayloadLength));
+    }
+
+    #[test]
+    fn set_name_rejects_a_name_longer_than_name_size() {
+        let mut variable: Variable<Real> =
+            Variable::new("A", Real::from_bytes(&real_bytes()).unwrap()).unwrap();
+        assert_eq!(variable.set_name("TOOLONGNAME"), Err(NameError::TooLong));
+    }
+
+    #[test]
+    fn set_name_rejects_a_name_starting_with_a_digit() {
+        let mut variable: Variable<Real> =
+            Variable::new("A", Real::from_bytes(&real_bytes()).unwrap()).unwrap();
+        assert_eq!(variable.set_name("1A"), Err(NameError::InvalidToken));
+    }
+
+    #[test]
+    fn set_name_rejects_an_invalid_character() {
+        let mut variable: Variable<Real> =
+            Variable::new("A", Real::from_bytes(&real_bytes()).unwrap()).unwrap();
+        assert_eq!(variable.set_name("A-B"), Err(NameError::InvalidToken));
+    }
+
+    #[test]
+    fn set_name_accepts_theta() {
+        let mut variable: Variable<Real> =
+            Variable::new("A", Real::from_bytes(&real_bytes()).unwrap()).unwrap();
+        assert!(variable.set_name("\u{5B}").is_ok());
+    }
+
+    fn variable_header_bytes(type_id: u8, length: u16, length_2: u16) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend(length.to_le_bytes());
+        bytes.extend(length_2.to_le_bytes());
+        bytes.push(type_id);
+        bytes.extend([b'A', 0, 0, 0, 0, 0, 0, 0]); // name, padded
+        bytes.push(0); // version
+        bytes.push(0); // archive flag
+        bytes.extend(real_bytes());
+        bytes
+    }
+
+    #[test]
+    fn from_bytes_rejects_a_mismatched_type_id_in_error_mode() {
+        let bytes = variable_header_bytes(Program::TYPE_ID, Real::SIZE as u16, Real::SIZE as u16);
+        let result: Result<Variable<Real>, ReadError> =
+            Variable::from_bytes(&bytes, &VariableReadOptions::default());
+        assert_eq!(result.err(), Some(ReadError::InvalidTypeId));
+    }
+
+    #[test]
+    fn from_bytes_accepts_a_mismatched_type_id_in_ignore_mode() {
+        let bytes = variable_header_bytes(Program::TYPE_ID, Real::SIZE as u16, Real::SIZE as u16);
+        let opts = VariableReadOptions {
+            header: unsafe { ReadMode::ignore() },
+        };
+        let variable: Variable<Real> = Variable::from_bytes(&bytes, &opts).unwrap();
+        assert_eq!(variable.type_id(), Program::TYPE_ID);
+    }
+
+    #[test]
+    fn from_bytes_fixes_a_mismatched_type_id_in_fix_mode() {
+        let bytes = variable_header_bytes(Program::TYPE_ID, Real::SIZE as u16, Real::SIZE as u16);
+        let opts = VariableReadOptions {
+            header: ReadMode::fix(),
+        };
+        let variable: Variable<Real> = Variable::from_bytes(&bytes, &opts).unwrap();
+        assert_eq!(variable.type_id(), Real::TYPE_ID);
+    }
+
+    #[test]
+    fn from_bytes_rejects_mismatched_length_fields_in_error_mode() {
+        let bytes = variable_header_bytes(Real::TYPE_ID, Real::SIZE as u16, Real::SIZE as u16 - 1);
+        let result: Result<Variable<Real>, ReadError> =
+            Variable::from_bytes(&bytes, &VariableReadOptions::default());
+        assert_eq!(result.err(), Some(ReadError::MismatchedLengthFields));
+    }
+
+    #[test]
+    fn from_bytes_accepts_mismatched_length_fields_in_ignore_mode() {
+        let bytes = variable_header_bytes(Real::TYPE_ID, Real::SIZE as u16, Real::SIZE as u16 - 1);
+        let opts = VariableReadOptions {
+            header: unsafe { ReadMode::ignore() },
+        };
+        let variable: Variable<Real> = Variable::from_bytes(&bytes, &opts).unwrap();
+        assert_eq!(variable.name().unwrap(), "A");
+    }
+
+    #[test]
+    fn from_bytes_fixes_mismatched_length_fields_in_fix_mode() {
+        // The second field disagrees by claiming more data than the first; fix mode should
+        // reconcile to the smaller, correct length rather than trusting either blindly.
+        let bytes = variable_header_bytes(Real::TYPE_ID, Real::SIZE as u16, Real::SIZE as u16 + 1);
+        let opts = VariableReadOptions {
+            header: ReadMode::fix(),
+        };
+        let variable: Variable<Real> = Variable::from_bytes(&bytes, &opts).unwrap();
+        assert_eq!(variable.name().unwrap(), "A");
+    }
+
+    #[test]
+    fn new_then_bytes_then_from_bytes_round_trips() {
+        let variable: Variable<Real> =
+            Variable::new("THETA", Real::from_bytes(&real_bytes()).unwrap()).unwrap();
+        let bytes = variable.bytes();
+        let parsed: Variable<Real> =
+            Variable::from_bytes(&bytes, &VariableReadOptions::default()).unwrap();
+        assert_eq!(parsed.name().unwrap(), "THETA");
+        assert_eq!(parsed.type_id(), Real::TYPE_ID);
+    }
+}
+
+#[cfg(test)]
+mod comment_tests {
+    use super::*;
+
+    #[test]
+    fn new_comment_is_empty_and_zero_terminated() {
+        let comment = Comment::new();
+        assert!(comment.is_empty());
+        assert_eq!(comment.as_str().unwrap(), "");
+        assert_eq!(comment.termination(), Termination::ZeroTerminated);
+    }
+
+    #[test]
+    fn push_str_reports_full_length_when_it_fits() {
+        let mut comment = Comment::new();
+        let taken = comment.push_str("hello");
+        assert_eq!(taken, 5);
+        assert_eq!(comment.as_str().unwrap(), "hello");
+        assert_eq!(comment.len(), 5);
+    }
+
+    #[test]
+    fn push_str_truncates_on_a_char_boundary() {
+        let mut comment = Comment::new();
+        comment.push_str(&"A".repeat(Comment::CAPACITY - 1));
+        let taken = comment.push_str("\u{20AC}"); // 3-byte euro sign, only 1 byte of room left
+        assert_eq!(taken, 0);
+        assert!(comment.as_str().is_ok());
+        assert_eq!(comment.len(), Comment::CAPACITY - 1);
+    }
+
+    #[test]
+    fn push_str_across_calls_never_splits_a_multibyte_char() {
+        let mut comment = Comment::new();
+        comment.push_str(&"A".repeat(Comment::CAPACITY - 2));
+        let taken = comment.push_str("\u{20AC}"); // needs 3 bytes, only 2 remain
+        assert_eq!(taken, 0);
+        assert!(comment.as_str().is_ok());
+    }
+
+    #[test]
+    fn clear_empties_a_nonempty_comment() {
+        let mut comment = Comment::new();
+        comment.push_str("hello");
+        comment.clear();
+        assert!(comment.is_empty());
+        assert_eq!(comment.as_str().unwrap(), "");
+    }
+
+    #[test]
+    fn from_raw_infers_zero_terminated() {
+        let mut raw = [b'X'; Comment::CAPACITY];
+        raw[3] = 0;
+        let comment = Comment::from_raw(raw);
+        assert_eq!(comment.termination(), Termination::ZeroTerminated);
+        assert_eq!(comment.as_str().unwrap(), "XXX");
+    }
+
+    #[test]
+    fn from_raw_infers_padded() {
+        let mut raw = [b' '; Comment::CAPACITY];
+        raw[..3].copy_from_slice(b"abc");
+        let comment = Comment::from_raw(raw);
+        assert_eq!(comment.termination(), Termination::Padded);
+        assert_eq!(comment.as_str().unwrap(), "abc");
+    }
+
+    #[test]
+    fn to_raw_then_from_raw_round_trips() {
+        let mut comment = Comment::new();
+        comment.push_str("note");
+        let raw = comment.to_raw();
+        let parsed = Comment::from_raw(raw);
+        assert_eq!(parsed.as_str().unwrap(), "note");
+        assert_eq!(parsed.termination(), Termination::ZeroTerminated);
+    }
+
+    #[test]
+    fn set_termination_rewrites_padding() {
+        let mut comment = Comment::new();
+        comment.push_str("hi");
+        comment.set_termination(Termination::Padded);
+        let raw = comment.to_raw();
+        assert_eq!(raw[Comment::CAPACITY - 1], b' ');
+        assert_eq!(Comment::from_raw(raw).termination(), Termination::Padded);
+    }
 }